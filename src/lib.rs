@@ -1,4 +1,4 @@
-use anyhow::{bail, Context, Error, Result};
+use anyhow::{bail, Context, Result};
 use circular_buffer::CircularBuffer;
 use clap::Args;
 use crossterm::{
@@ -6,12 +6,23 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use derive_more::{From, Into};
 use std::fmt;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 use serialport::{ClearBuffer, SerialPort, SerialPortInfo, SerialPortType};
-use std::{io, str::from_utf8, time, time::Duration};
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::from_utf8,
+    sync::{mpsc, Arc, Mutex},
+    thread, time,
+    time::Duration,
+};
+use uom::si::{
+    electric_current::ampere, electric_potential::volt, energy::watt_hour, f64::ElectricCurrent,
+    f64::ElectricPotential, f64::Energy as UomEnergy, f64::Power as UomPower, power::watt,
+};
 
 pub fn list_ports(args: &ListArgs) -> Result<()> {
     let ports =
@@ -49,19 +60,24 @@ pub fn guess_port() -> Result<String> {
 
 fn run_app<B: Backend>(
     terminal: &mut Terminal<B>,
-    powsup: &mut PowSup,
+    worker: &Worker,
     args: &InteractiveArgs,
 ) -> Result<()> {
     let mut last_tick = time::Instant::now();
     let mut last_powercycle: Option<time::Instant> = None;
+    let mut y_max_offset: f64 = 0.0;
+    let mut show_power = false;
     loop {
         if last_tick.elapsed() >= args.period {
-            terminal.draw(|f| update_tui(f, powsup))?;
+            let state = worker.snapshot();
+            let port_name = worker.port_name.as_deref();
+            terminal
+                .draw(|f| update_tui(f, &state, port_name, &mut y_max_offset, show_power))?;
             last_tick = time::Instant::now();
         }
         if let Some(last_pc) = last_powercycle {
             if last_pc.elapsed() >= args.off_duration {
-                powsup.on()?;
+                worker.send(Command::On);
                 last_powercycle = None;
             }
         }
@@ -73,14 +89,30 @@ fn run_app<B: Backend>(
         if event::poll(timeout)? {
             if let Event::Key(key) = event::read()? {
                 match key.code {
-                    KeyCode::Char('p') => powsup.on()?,
-                    KeyCode::Char('n') => powsup.off()?,
+                    KeyCode::Char('p') => worker.send(Command::On),
+                    KeyCode::Char('n') => worker.send(Command::Off),
                     KeyCode::Char('c') => {
-                        powsup.off()?;
+                        worker.send(Command::Off);
                         last_powercycle = Some(time::Instant::now());
                     }
-                    KeyCode::Char('j') => powsup.y_max_offset -= 1.0,
-                    KeyCode::Char('k') => powsup.y_max_offset += 1.0,
+                    KeyCode::Char('j') => y_max_offset -= 1.0,
+                    KeyCode::Char('k') => y_max_offset += 1.0,
+                    KeyCode::Char('w') => show_power = !show_power,
+                    KeyCode::Char('l') => {
+                        if let Some(file) = &args.log_file {
+                            if worker.snapshot().logging.is_none() {
+                                worker.send(Command::Log {
+                                    file: file.clone(),
+                                    period: args.log_period,
+                                    duration: args.log_duration,
+                                });
+                            }
+                        }
+                    }
+                    KeyCode::Up => nudge_voltage(worker, 0.1),
+                    KeyCode::Down => nudge_voltage(worker, -0.1),
+                    KeyCode::Right => nudge_current(worker, 0.1),
+                    KeyCode::Left => nudge_current(worker, -0.1),
                     KeyCode::Char('q') => return Ok(()),
                     _other => (),
                 }
@@ -89,40 +121,61 @@ fn run_app<B: Backend>(
     }
 }
 
-fn update_tui(f: &mut Frame, powsup: &mut PowSup) {
+/// Nudge the voltage setpoint by `delta` volts; `set_voltage` rejects a result outside the
+/// supply's limits.
+fn nudge_voltage(worker: &Worker, delta: f64) {
+    if let Some((v, _)) = worker.snapshot().preset {
+        worker.send(Command::SetVoltage((f64::from(v) + delta).into()));
+    }
+}
+
+/// Nudge the current setpoint by `delta` amps; `set_current` rejects a result outside the
+/// supply's limits.
+fn nudge_current(worker: &Worker, delta: f64) {
+    if let Some((_, i)) = worker.snapshot().preset {
+        worker.send(Command::SetCurrent((f64::from(i) + delta).into()));
+    }
+}
+
+fn update_tui(
+    f: &mut Frame,
+    state: &WorkerState,
+    port_name: Option<&str>,
+    y_max_offset: &mut f64,
+    show_power: bool,
+) {
     let mut message: Vec<Line> = Vec::new();
-    let mut prt_err = |err: Error| {
+    if let Some(err) = &state.error {
         message.push(Line::from(Span::styled(
-            err.to_string(),
+            err.clone(),
             Style::default().fg(Color::Red),
-        )))
-    };
+        )));
+    }
 
-    let (max_v, max_i) = powsup.get_max().unwrap_or_else(|err| {
-        prt_err(err);
-        (Voltage(f64::NAN), Current(f64::NAN))
-    });
+    let (max_v, max_i) = state.max.unwrap_or((Voltage::from(f64::NAN), Current::from(f64::NAN)));
 
-    let display_out = powsup.get_out().unwrap_or_else(|err| {
-        prt_err(err);
-        "Error".to_string()
-    });
+    let display_out = match state.out {
+        Some(true) => "On",
+        Some(false) => "Off",
+        None => "--",
+    };
+
+    let (preset_v, preset_i) = state.preset.unwrap_or((Voltage::from(f64::NAN), Current::from(f64::NAN)));
 
-    let (preset_v, preset_i) = powsup.get_preset().unwrap_or_else(|err| {
-        prt_err(err);
-        (Voltage(f64::NAN), Current(f64::NAN))
-    });
+    let (display_v, display_i, display_mode) = state
+        .display
+        .clone()
+        .unwrap_or((Voltage::from(f64::NAN), Current::from(f64::NAN), String::from("--")));
 
-    let (display_v, display_i, display_mode) = powsup.get_display().unwrap_or_else(|err| {
-        prt_err(err);
-        (Voltage(f64::NAN), Current(f64::NAN), String::from("--"))
-    });
+    let power = state
+        .power
+        .unwrap_or_else(|| Power::from_vi(Voltage::from(f64::NAN), Current::from(f64::NAN)));
 
     let ppanes = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
             [
-                Constraint::Length(6),
+                Constraint::Length(8),
                 Constraint::Min(10),
                 Constraint::Length(5),
             ]
@@ -136,10 +189,7 @@ fn update_tui(f: &mut Frame, powsup: &mut PowSup) {
 
     let block = Block::default()
         .title(
-            powsup
-                .port
-                .name()
-                .map_or_else(|| " <unknown port> ".to_string(), |s| format!(" {s} ")),
+            port_name.map_or_else(|| " <unknown port> ".to_string(), |s| format!(" {s} ")),
         )
         .borders(Borders::ALL);
     let text = vec![
@@ -159,6 +209,14 @@ fn update_tui(f: &mut Frame, powsup: &mut PowSup) {
         Line::from(format!(
             "Actual:  {display_v}   {display_i}  {display_mode}  "
         )),
+        Line::from(format!("Power:   {power}   Energy: {}", state.energy)),
+        Line::from(format!(
+            "Logging: {}",
+            state
+                .logging
+                .as_ref()
+                .map_or_else(|| "--".to_string(), |p| p.display().to_string())
+        )),
     ];
     let paragraph = Paragraph::new(text.clone())
         .alignment(Alignment::Center)
@@ -172,8 +230,10 @@ fn update_tui(f: &mut Frame, powsup: &mut PowSup) {
     let text = vec![
         Line::from("p => Power on     j => Zoom in (y-axis) "),
         Line::from("n => Power off    k => Zoom out (y-axis)"),
-        Line::from("c => Power cycle                        "),
-        Line::from("q => Quit                               "),
+        Line::from("c => Power cycle  Up/Down => Nudge volt "),
+        Line::from("q => Quit         Left/Right => Nudge A "),
+        Line::from("w => Toggle A/W chart                   "),
+        Line::from("l => Start logging (--log-file)         "),
     ];
     let paragraph = Paragraph::new(text.clone())
         .alignment(Alignment::Center)
@@ -181,12 +241,24 @@ fn update_tui(f: &mut Frame, powsup: &mut PowSup) {
     f.render_widget(paragraph, panes[1]);
 
     // middle block
-    if powsup.y_max_offset + f64::from(preset_i) < 1.0 {
-        powsup.y_max_offset = - f64::from(preset_i) + 1.0;
+    let axis_ref = if show_power {
+        Power::from_vi(preset_v, preset_i).watts()
+    } else {
+        f64::from(preset_i)
+    };
+    if *y_max_offset + axis_ref < 1.0 {
+        *y_max_offset = -axis_ref + 1.0;
     }
-    let y_max: f64 = f64::from(preset_i) + powsup.y_max_offset;
-    let data: Vec<(f64, f64)> = std::iter::zip(1..300, &powsup.trend)
-        .map(|(x, (_, i))| (x.into(), (*i).into()))
+    let y_max: f64 = axis_ref + *y_max_offset;
+    let data: Vec<(f64, f64)> = std::iter::zip(1..300, &state.trend)
+        .map(|(x, (_, v, i))| {
+            let y = if show_power {
+                Power::from_vi(*v, *i).watts()
+            } else {
+                f64::from(*i)
+            };
+            (x.into(), y)
+        })
         .collect();
     let datasets = vec![Dataset::default()
         .marker(symbols::Marker::Braille)
@@ -197,7 +269,7 @@ fn update_tui(f: &mut Frame, powsup: &mut PowSup) {
         .x_axis(Axis::default().bounds([1.0, 300.0]))
         .y_axis(
             Axis::default()
-                .title("A")
+                .title(if show_power { "W" } else { "A" })
                 .labels(vec![
                     Span::raw("0"),
                     Span::raw(format!("{}", y_max * 0.25)),
@@ -227,48 +299,291 @@ fn is_powersupply(SerialPortInfo { port_type, .. }: &SerialPortInfo) -> bool {
     }
 }
 
-#[derive(Debug, Clone, Copy, From, Into, PartialEq)]
-pub struct Current(f64);
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Current(ElectricCurrent);
+
+impl From<f64> for Current {
+    fn from(amps: f64) -> Current {
+        Current(ElectricCurrent::new::<ampere>(amps))
+    }
+}
+
+impl From<Current> for f64 {
+    fn from(current: Current) -> f64 {
+        current.0.get::<ampere>()
+    }
+}
 
 impl fmt::Display for Current {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:5.2} A", self.0)
+        write!(f, "{:5.2} A", self.0.get::<ampere>())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Current {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0.get::<ampere>())
     }
 }
 
-#[derive(Debug, Clone, Copy, From, Into, PartialEq)]
-pub struct Voltage(f64);
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Voltage(ElectricPotential);
+
+impl From<f64> for Voltage {
+    fn from(volts: f64) -> Voltage {
+        Voltage(ElectricPotential::new::<volt>(volts))
+    }
+}
+
+impl From<Voltage> for f64 {
+    fn from(voltage: Voltage) -> f64 {
+        voltage.0.get::<volt>()
+    }
+}
 
 impl fmt::Display for Voltage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:5.2} V", self.0)
+        write!(f, "{:5.2} V", self.0.get::<volt>())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Voltage {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0.get::<volt>())
+    }
+}
+
+/// Instantaneous power, derived from a [`Voltage`]/[`Current`] pair (`P = V*I`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Power(UomPower);
+
+impl Power {
+    fn from_vi(voltage: Voltage, current: Current) -> Power {
+        Power(voltage.0 * current.0)
+    }
+
+    fn watts(self) -> f64 {
+        self.0.get::<watt>()
+    }
+}
+
+impl fmt::Display for Power {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:6.2} W", self.0.get::<watt>())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Power {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0.get::<watt>())
+    }
+}
+
+/// Energy accumulated over time by integrating [`Power`] samples with the trapezoidal rule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Energy(UomEnergy);
+
+impl Energy {
+    fn zero() -> Energy {
+        Energy(UomEnergy::new::<watt_hour>(0.0))
+    }
+
+    /// Integrate the energy consumed between two power samples `Δt` seconds apart, using the
+    /// trapezoidal rule: `Wh += 0.5*(P_prev+P_now)*Δt/3600`.
+    fn accumulate(&mut self, previous: Power, current: Power, dt: Duration) {
+        let wh = 0.5 * (previous.0.get::<watt>() + current.0.get::<watt>()) * dt.as_secs_f64()
+            / 3600.0;
+        self.0 += UomEnergy::new::<watt_hour>(wh);
+    }
+}
+
+impl fmt::Display for Energy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:7.3} Wh", self.0.get::<watt_hour>())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Energy {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0.get::<watt_hour>())
     }
 }
 
+/// Write the CSV header row for a `Command::Log` capture.
+fn write_log_header(writer: &mut impl Write) -> io::Result<()> {
+    writeln!(writer, "elapsed_s,voltage_v,current_a,power_w,mode")
+}
+
+/// Write one CSV sample row for a `Command::Log` capture.
+fn write_log_row(
+    writer: &mut impl Write,
+    elapsed: Duration,
+    voltage: Voltage,
+    current: Current,
+    power: Power,
+    mode: &str,
+) -> io::Result<()> {
+    writeln!(
+        writer,
+        "{:.3},{:.2},{:.2},{:.2},{mode}",
+        elapsed.as_secs_f64(),
+        f64::from(voltage),
+        f64::from(current),
+        power.watts(),
+    )
+}
+
+/// Append one CSV row for a `Command::Log` capture, tolerating a transient `get_display` error by
+/// skipping the sample instead of aborting the capture. Shared by the blocking [`PowSup::log`]
+/// and the interactive [`Worker`]'s per-tick log handling, so an overnight soak test survives a
+/// marginal read instead of losing the rest of the capture.
+fn log_sample(
+    writer: &mut impl Write,
+    elapsed: Duration,
+    display: Result<(Voltage, Current, String)>,
+) -> io::Result<()> {
+    match display {
+        Ok((voltage, current, mode)) => {
+            let power = Power::from_vi(voltage, current);
+            write_log_row(writer, elapsed, voltage, current, power, &mode)?;
+            writer.flush()
+        }
+        Err(err) => {
+            log::warn!("Skipping log sample: {err}");
+            Ok(())
+        }
+    }
+}
+
+/// A power-supply command, issued either from the CLI or programmatically when `powsup` is used
+/// as a library.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    On,
+    Off,
+    Powercycle(Duration),
+    SetVoltage(Voltage),
+    SetCurrent(Current),
+    SetOvp(Voltage),
+    SetOcp(Current),
+    GetDisplay,
+    GetPreset,
+    GetMax,
+    GetOut,
+    GetOvp,
+    GetOcp,
+    /// Record timestamped `GETD` samples to a CSV file, sampling every `period` for `duration`.
+    Log {
+        file: PathBuf,
+        period: Duration,
+        duration: Duration,
+    },
+}
+
+/// The structured result of executing a [`Command`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Reply {
+    Ok,
+    Display {
+        voltage: Voltage,
+        current: Current,
+        mode: String,
+    },
+    Preset {
+        voltage: Voltage,
+        current: Current,
+    },
+    Max {
+        voltage: Voltage,
+        current: Current,
+    },
+    Out {
+        on: bool,
+    },
+    Ovp {
+        voltage: Voltage,
+    },
+    Ocp {
+        current: Current,
+    },
+}
+
+/// Per-read timeout that the `powsup` CLI hands to [`PowSup::new`] when `--timeout` is not
+/// given. [`PowSup::new`] itself does not apply it: a `None` timeout uses `read_deadline`.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(20);
+
+/// Overall deadline for assembling a complete reply out of possibly-many short reads.
+pub const DEFAULT_READ_DEADLINE: Duration = Duration::from_millis(380);
+
+/// Format a setpoint value (volts or amps) as the HCS protocol's tenths-scaled 3-digit string,
+/// e.g. `12.3` becomes `"123"`.
+fn format_tenths(value: f64) -> String {
+    format!("{:03.0}", value * 10.0)
+}
+
+/// Assemble a complete `OK\r`-terminated reply out of chunks produced by `read_chunk`, retrying
+/// on `TimedOut` until `read_deadline` elapses. Factored out of [`PowSup::read`] so the
+/// retry/deadline logic can be tested without a real serial port.
+fn read_until_ok(
+    read_deadline: Duration,
+    mut read_chunk: impl FnMut() -> io::Result<Vec<u8>>,
+) -> Result<String> {
+    let deadline = time::Instant::now() + read_deadline;
+    let mut s = String::new();
+    let mut is_incomplete = true;
+    while time::Instant::now() < deadline {
+        match read_chunk() {
+            Ok(buf) => {
+                s.push_str(from_utf8(&buf)?);
+                if s.ends_with("OK\r") {
+                    is_incomplete = false;
+                    break;
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => continue,
+            Err(e) => return Err(e).context("Read from serial port failed."),
+        }
+    }
+    if is_incomplete {
+        bail!(
+            "Incomplete reply from power-supply after {:?}: {:?}",
+            read_deadline,
+            &s
+        )
+    };
+    Ok(s)
+}
+
 pub struct PowSup {
     port: Box<dyn SerialPort>,
     cached_max: Option<(Voltage, Current)>,
-    trend: CircularBuffer<300, (Voltage, Current)>,
-    y_max_offset: f64,
+    read_deadline: Duration,
 }
 
 impl PowSup {
-    pub fn new(port: &str) -> Result<PowSup> {
+    /// Open `port`. `timeout` is the per-read timeout handed to the underlying serial port;
+    /// `None` blocks each read for up to `read_deadline` instead of applying [`DEFAULT_TIMEOUT`],
+    /// so `read_until_ok`'s deadline check always gets a chance to run.
+    pub fn new(port: &str, timeout: Option<Duration>, read_deadline: Duration) -> Result<PowSup> {
         log::trace!("opening port");
         let port = serialport::new(port, 9600)
             .data_bits(serialport::DataBits::Eight)
             .stop_bits(serialport::StopBits::One)
             .parity(serialport::Parity::None)
             .flow_control(serialport::FlowControl::None)
-            .timeout(Duration::from_millis(20))
+            .timeout(timeout.unwrap_or(read_deadline))
             .open()
             .with_context(|| format!("Failed to open the serial port \"{port}\""))?;
         port.clear(ClearBuffer::All)?;
         Ok(PowSup {
             port,
             cached_max: Option::None,
-            trend: CircularBuffer::new(),
-            y_max_offset: 0.0,
+            read_deadline,
         })
     }
 
@@ -280,26 +595,16 @@ impl PowSup {
     }
 
     fn read(&mut self) -> Result<String> {
-        let mut s = String::new();
-        let mut is_incomplete = true;
-        for i in 1..20 {
+        let port = &mut self.port;
+        let mut i = 0;
+        let s = read_until_ok(self.read_deadline, || {
+            i += 1;
             let mut buf: Vec<u8> = vec![0; 32];
-            self.port
-                .read(buf.as_mut_slice())
-                .context("Read from serial port failed.")?;
-            log::trace!("read: #{} got {:?}", &i, &buf);
-            s.push_str(from_utf8(
-                &buf.into_iter().take_while(|&x| x != 0).collect::<Vec<u8>>(),
-            )?);
-            if s.ends_with("OK\r") {
-                is_incomplete = false;
-                break;
-            }
-        }
+            port.read(buf.as_mut_slice())?;
+            log::trace!("read: #{} got {:?}", i, &buf);
+            Ok(buf.into_iter().take_while(|&x| x != 0).collect())
+        })?;
         log::debug!("read: got {:?}", &s);
-        if is_incomplete {
-            bail!("Incomplete reply from power-supply: {:?}", &s)
-        };
         Ok(s)
     }
 
@@ -356,7 +661,6 @@ impl PowSup {
             "1" => String::from("CC"),
             _other => bail!("Failed to parse const-current mode from reply"),
         };
-        self.trend.push_back((v, c));
         Ok((v, c, cc))
     }
 
@@ -423,28 +727,228 @@ impl PowSup {
         }
     }
 
-    pub fn status(&mut self, brief: bool) -> Result<()> {
-        if !brief {
-            let (v, i) = self.get_max()?;
-            println!("Maximum: {v}  {i}");
-            let (v, i) = self.get_preset()?;
-            println!("Preset:  {v}  {i}");
+    /// Program a new voltage setpoint; a negative value or one above the supply's maximum
+    /// (`GMAX`) is rejected with an error.
+    pub fn set_voltage(&mut self, voltage: Voltage) -> Result<()> {
+        let (max_v, _) = self.get_max()?;
+        if f64::from(voltage) < 0.0 || f64::from(voltage) > f64::from(max_v) {
+            bail!(
+                "Requested voltage {voltage} is out of range (0.00 V .. {max_v})"
+            );
+        }
+        self.write(&format!("VOLT{}\r", format_tenths(f64::from(voltage))))?;
+        self.expect_ok()
+    }
+
+    /// Program a new current setpoint; a negative value or one above the supply's maximum
+    /// (`GMAX`) is rejected with an error.
+    pub fn set_current(&mut self, current: Current) -> Result<()> {
+        let (_, max_i) = self.get_max()?;
+        if f64::from(current) < 0.0 || f64::from(current) > f64::from(max_i) {
+            bail!(
+                "Requested current {current} is out of range (0.00 A .. {max_i})"
+            );
+        }
+        self.write(&format!("CURR{}\r", format_tenths(f64::from(current))))?;
+        self.expect_ok()
+    }
+
+    /// Read the over-voltage protection threshold (`GOVP`).
+    pub fn get_ovp(&mut self) -> Result<Voltage> {
+        self.write("GOVP\r")?;
+        let reply = self.read()?;
+        if reply.len() != 6 || &reply[3..] != "OK\r" {
+            bail!(
+                "Got an unexpected GOVP reply from the power-supply: {:?}",
+                &reply
+            );
+        }
+        let v = format!("{}.{}", &reply[0..2], &reply[2..3])
+            .parse::<f64>()
+            .context("Failed to parse OVP voltage from reply")?
+            .into();
+        Ok(v)
+    }
+
+    /// Program a new over-voltage protection threshold (`SOVP`); a negative value or one above
+    /// the supply's maximum (`GMAX`) is rejected with an error.
+    pub fn set_ovp(&mut self, voltage: Voltage) -> Result<()> {
+        let (max_v, _) = self.get_max()?;
+        if f64::from(voltage) < 0.0 || f64::from(voltage) > f64::from(max_v) {
+            bail!(
+                "Requested OVP threshold {voltage} is out of range (0.00 V .. {max_v})"
+            );
+        }
+        self.write(&format!("SOVP{}\r", format_tenths(f64::from(voltage))))?;
+        self.expect_ok()
+    }
+
+    /// Read the over-current protection threshold (`GOCP`).
+    pub fn get_ocp(&mut self) -> Result<Current> {
+        self.write("GOCP\r")?;
+        let reply = self.read()?;
+        if reply.len() != 6 || &reply[3..] != "OK\r" {
+            bail!(
+                "Got an unexpected GOCP reply from the power-supply: {:?}",
+                &reply
+            );
+        }
+        let c = format!("{}.{}", &reply[0..2], &reply[2..3])
+            .parse::<f64>()
+            .context("Failed to parse OCP current from reply")?
+            .into();
+        Ok(c)
+    }
+
+    /// Program a new over-current protection threshold (`SOCP`); a negative value or one above
+    /// the supply's maximum (`GMAX`) is rejected with an error.
+    pub fn set_ocp(&mut self, current: Current) -> Result<()> {
+        let (_, max_i) = self.get_max()?;
+        if f64::from(current) < 0.0 || f64::from(current) > f64::from(max_i) {
+            bail!(
+                "Requested OCP threshold {current} is out of range (0.00 A .. {max_i})"
+            );
+        }
+        self.write(&format!("SOCP{}\r", format_tenths(f64::from(current))))?;
+        self.expect_ok()
+    }
+
+    /// Record timestamped `GETD` samples to `path` every `period`, for `duration`.
+    pub fn log(&mut self, path: &Path, period: Duration, duration: Duration) -> Result<()> {
+        let mut file = File::create(path)
+            .with_context(|| format!("Failed to create log file \"{}\"", path.display()))?;
+        write_log_header(&mut file)?;
+        file.flush()?;
+        let start = time::Instant::now();
+        while start.elapsed() < duration {
+            log_sample(&mut file, start.elapsed(), self.get_display())?;
+            thread::sleep(period);
+        }
+        Ok(())
+    }
+
+    /// Execute a [`Command`] and return its structured [`Reply`].
+    ///
+    /// This is the typed entry point that `status()` and other consumers are built on, and the
+    /// one library users embedding `powsup` in their own programs should call.
+    pub fn execute(&mut self, command: Command) -> Result<Reply> {
+        match command {
+            Command::On => self.on().map(|()| Reply::Ok),
+            Command::Off => self.off().map(|()| Reply::Ok),
+            Command::Powercycle(duration) => self.powercycle(duration).map(|()| Reply::Ok),
+            Command::SetVoltage(voltage) => self.set_voltage(voltage).map(|()| Reply::Ok),
+            Command::SetCurrent(current) => self.set_current(current).map(|()| Reply::Ok),
+            Command::SetOvp(voltage) => self.set_ovp(voltage).map(|()| Reply::Ok),
+            Command::SetOcp(current) => self.set_ocp(current).map(|()| Reply::Ok),
+            Command::GetDisplay => self
+                .get_display()
+                .map(|(voltage, current, mode)| Reply::Display { voltage, current, mode }),
+            Command::GetPreset => self
+                .get_preset()
+                .map(|(voltage, current)| Reply::Preset { voltage, current }),
+            Command::GetMax => self
+                .get_max()
+                .map(|(voltage, current)| Reply::Max { voltage, current }),
+            Command::GetOut => self.get_out().map(|out| Reply::Out { on: out == "On" }),
+            Command::GetOvp => self.get_ovp().map(|voltage| Reply::Ovp { voltage }),
+            Command::GetOcp => self.get_ocp().map(|current| Reply::Ocp { current }),
+            Command::Log {
+                file,
+                period,
+                duration,
+            } => self.log(&file, period, duration).map(|()| Reply::Ok),
+        }
+    }
+
+    pub fn status(&mut self, brief: bool, json: bool) -> Result<()> {
+        let max = if brief { None } else { Some(self.execute(Command::GetMax)?) };
+        let protect = if brief {
+            None
+        } else {
+            Some((self.execute(Command::GetOvp)?, self.execute(Command::GetOcp)?))
+        };
+        let preset = if brief { None } else { Some(self.execute(Command::GetPreset)?) };
+        let display = self.execute(Command::GetDisplay)?;
+
+        let power = if let Reply::Display { voltage, current, .. } = &display {
+            Some(Power::from_vi(*voltage, *current))
+        } else {
+            None
+        };
+        // No accumulated `Energy` here: this is a one-shot snapshot with a single power sample,
+        // and the trapezoidal integration needs two. Accumulated Wh is only meaningful for the
+        // interactive `Worker`, which polls continuously.
+
+        if json {
+            return self.print_status_json(&max, &protect, &preset, &display, &power);
+        }
+
+        if let Some(Reply::Max { voltage, current }) = max {
+            println!("Maximum: {voltage}  {current}");
+        }
+        if let Some((Reply::Ovp { voltage }, Reply::Ocp { current })) = protect {
+            println!("Protect: {voltage}  {current}");
+        }
+        if let Some(Reply::Preset { voltage, current }) = preset {
+            println!("Preset:  {voltage}  {current}");
+        }
+        if let Reply::Display { voltage, current, mode } = display {
+            let power = power.expect("power is always computed alongside the display reading");
+            println!("Display: {voltage}  {current}  {power}  {mode}");
         }
-        let (v, i, cc) = self.get_display()?;
-        println!("Display: {v}  {i}  {cc}");
         Ok(())
     }
 
-    pub fn interactive(&mut self, args: &InteractiveArgs) -> Result<()> {
+    #[cfg(feature = "serde")]
+    fn print_status_json(
+        &self,
+        max: &Option<Reply>,
+        protect: &Option<(Reply, Reply)>,
+        preset: &Option<Reply>,
+        display: &Reply,
+        power: &Option<Power>,
+    ) -> Result<()> {
+        #[derive(serde::Serialize)]
+        struct StatusJson<'a> {
+            max: &'a Option<Reply>,
+            protect: &'a Option<(Reply, Reply)>,
+            preset: &'a Option<Reply>,
+            display: &'a Reply,
+            power: &'a Option<Power>,
+        }
+        let status = StatusJson { max, protect, preset, display, power };
+        println!(
+            "{}",
+            serde_json::to_string(&status).context("Failed to serialize status to JSON")?
+        );
+        Ok(())
+    }
+
+    #[cfg(not(feature = "serde"))]
+    fn print_status_json(
+        &self,
+        _max: &Option<Reply>,
+        _protect: &Option<(Reply, Reply)>,
+        _preset: &Option<Reply>,
+        _display: &Reply,
+        _power: &Option<Power>,
+    ) -> Result<()> {
+        bail!("The `--json` flag requires powsup to be built with the `serde` feature enabled")
+    }
+
+    /// Run in interactive (TUI) mode, handing the serial port off to a background [`Worker`].
+    pub fn interactive(self, args: &InteractiveArgs) -> Result<()> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
         execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
+        let worker = Worker::spawn(self);
+
         // Do NOT unwrap the result here, only after we have put the
         // console back in a proper state.
-        let result = run_app(&mut terminal, self, args);
+        let result = run_app(&mut terminal, &worker, args);
 
         disable_raw_mode()?;
         execute!(
@@ -458,6 +962,211 @@ impl PowSup {
     }
 }
 
+/// A message sent from the UI thread to the [`Worker`] thread.
+enum WorkerMessage {
+    Execute(Command),
+    Shutdown,
+}
+
+/// The worker's latest view of the power-supply, shared with the UI thread.
+#[derive(Clone)]
+struct WorkerState {
+    display: Option<(Voltage, Current, String)>,
+    preset: Option<(Voltage, Current)>,
+    max: Option<(Voltage, Current)>,
+    out: Option<bool>,
+    trend: CircularBuffer<300, (time::Instant, Voltage, Current)>,
+    power: Option<Power>,
+    energy: Energy,
+    /// The file an in-progress `Command::Log` capture is being written to, if any.
+    logging: Option<PathBuf>,
+    error: Option<String>,
+}
+
+impl Default for WorkerState {
+    fn default() -> WorkerState {
+        WorkerState {
+            display: None,
+            preset: None,
+            max: None,
+            out: None,
+            trend: CircularBuffer::new(),
+            power: None,
+            energy: Energy::zero(),
+            logging: None,
+            error: None,
+        }
+    }
+}
+
+/// Worker-local bookkeeping for an in-progress `Command::Log` capture.
+struct LogSession {
+    writer: File,
+    start: time::Instant,
+    last_sample: Option<time::Instant>,
+    period: Duration,
+    duration: Duration,
+}
+
+/// Owns the [`PowSup`]'s serial port on a dedicated thread so the TUI never blocks on I/O.
+struct Worker {
+    state: Arc<Mutex<WorkerState>>,
+    commands: mpsc::Sender<WorkerMessage>,
+    handle: Option<thread::JoinHandle<()>>,
+    port_name: Option<String>,
+}
+
+impl Worker {
+    fn spawn(mut powsup: PowSup) -> Worker {
+        let port_name = powsup.port.name();
+        let state = Arc::new(Mutex::new(WorkerState::default()));
+        let (commands, rx) = mpsc::channel();
+        let worker_state = Arc::clone(&state);
+        let handle = thread::spawn(move || {
+            let mut last_sample: Option<(time::Instant, Power)> = None;
+            let mut log: Option<LogSession> = None;
+            loop {
+                loop {
+                    match rx.try_recv() {
+                        Ok(WorkerMessage::Execute(Command::Log {
+                            file,
+                            period,
+                            duration,
+                        })) => {
+                            if log.is_some() {
+                                worker_state.lock().unwrap().error = Some(format!(
+                                    "Ignored log request for \"{}\": a capture is already in progress",
+                                    file.display()
+                                ));
+                            } else {
+                                let session = File::create(&file)
+                                    .with_context(|| {
+                                        format!("Failed to create log file \"{}\"", file.display())
+                                    })
+                                    .and_then(|mut writer| {
+                                        write_log_header(&mut writer)?;
+                                        writer.flush()?;
+                                        Ok(LogSession {
+                                            writer,
+                                            start: time::Instant::now(),
+                                            last_sample: None,
+                                            period,
+                                            duration,
+                                        })
+                                    });
+                                let mut snapshot = worker_state.lock().unwrap();
+                                match session {
+                                    Ok(session) => {
+                                        snapshot.logging = Some(file);
+                                        log = Some(session);
+                                    }
+                                    Err(err) => snapshot.error = Some(err.to_string()),
+                                }
+                            }
+                        }
+                        Ok(WorkerMessage::Execute(command)) => {
+                            if let Err(err) = powsup.execute(command) {
+                                worker_state.lock().unwrap().error = Some(err.to_string());
+                            }
+                        }
+                        Ok(WorkerMessage::Shutdown) => return,
+                        Err(mpsc::TryRecvError::Empty) => break,
+                        Err(mpsc::TryRecvError::Disconnected) => return,
+                    }
+                }
+
+                // Re-queried every tick (not just once at startup) so a transient failure right
+                // after the TUI opens self-heals once `PowSup`'s cache populates; it's free once
+                // cached.
+                let max = powsup.get_max();
+                let out = powsup.get_out();
+                let preset = powsup.get_preset();
+                let display = powsup.get_display();
+
+                let mut snapshot = worker_state.lock().unwrap();
+                match max {
+                    Ok(max) => snapshot.max = Some(max),
+                    Err(err) => snapshot.error = Some(err.to_string()),
+                }
+                match out {
+                    Ok(out) => snapshot.out = Some(out == "On"),
+                    Err(err) => snapshot.error = Some(err.to_string()),
+                }
+                match preset {
+                    Ok(preset) => snapshot.preset = Some(preset),
+                    Err(err) => snapshot.error = Some(err.to_string()),
+                }
+                match display {
+                    Ok((v, i, mode)) => {
+                        let now = time::Instant::now();
+                        let power = Power::from_vi(v, i);
+                        if let Some((last_time, last_power)) = last_sample {
+                            snapshot
+                                .energy
+                                .accumulate(last_power, power, now.duration_since(last_time));
+                        }
+                        last_sample = Some((now, power));
+                        snapshot.trend.push_back((now, v, i));
+                        snapshot.power = Some(power);
+                        snapshot.error = None;
+
+                        let mut log_finished = false;
+                        if let Some(session) = &mut log {
+                            let elapsed = session.start.elapsed();
+                            if elapsed >= session.duration {
+                                log_finished = true;
+                            } else if session
+                                .last_sample
+                                .map_or(true, |t| t.elapsed() >= session.period)
+                            {
+                                match log_sample(&mut session.writer, elapsed, Ok((v, i, mode.clone()))) {
+                                    Ok(()) => session.last_sample = Some(now),
+                                    Err(err) => {
+                                        snapshot.error = Some(err.to_string());
+                                        log_finished = true;
+                                    }
+                                }
+                            }
+                        }
+                        if log_finished {
+                            log = None;
+                            snapshot.logging = None;
+                        }
+
+                        snapshot.display = Some((v, i, mode));
+                    }
+                    Err(err) => snapshot.error = Some(err.to_string()),
+                }
+                drop(snapshot);
+            }
+        });
+        Worker {
+            state,
+            commands,
+            handle: Some(handle),
+            port_name,
+        }
+    }
+
+    /// Forward a command to the worker thread; failures surface via `WorkerState::error`.
+    fn send(&self, command: Command) {
+        let _ = self.commands.send(WorkerMessage::Execute(command));
+    }
+
+    fn snapshot(&self) -> WorkerState {
+        self.state.lock().unwrap().clone()
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        let _ = self.commands.send(WorkerMessage::Shutdown);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
 #[derive(Debug, Args)]
 pub struct ListArgs {
     /// List all available serial ports
@@ -476,6 +1185,15 @@ pub struct InteractiveArgs {
     /// The duration in milliseconds that the output should be turned off during powercycle
     #[clap(short, long, default_value = "3000", value_parser = ms_parser)]
     off_duration: Duration,
+    /// CSV file to record samples to; press `l` to start logging to it
+    #[clap(long)]
+    log_file: Option<PathBuf>,
+    /// The sampling period for `--log-file`, in milliseconds
+    #[clap(long, default_value = "1000", value_parser = ms_parser)]
+    log_period: Duration,
+    /// How long to log for once started with `l`, in milliseconds
+    #[clap(long, default_value = "28800000", value_parser = ms_parser)]
+    log_duration: Duration,
 }
 
 impl Default for InteractiveArgs {
@@ -483,6 +1201,9 @@ impl Default for InteractiveArgs {
         InteractiveArgs {
             period: Duration::from_millis(600),
             off_duration: Duration::from_millis(3000),
+            log_file: None,
+            log_period: Duration::from_millis(1000),
+            log_duration: Duration::from_millis(28_800_000),
         }
     }
 }
@@ -490,3 +1211,49 @@ impl Default for InteractiveArgs {
 pub fn ms_parser(ms: &str) -> std::result::Result<Duration, std::num::ParseIntError> {
     ms.parse().map(Duration::from_millis)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_until_ok_retries_past_a_timed_out_chunk() {
+        let mut chunks = vec![
+            Err(io::Error::from(io::ErrorKind::TimedOut)),
+            Ok(b"OK\r".to_vec()),
+        ]
+        .into_iter();
+        let s = read_until_ok(Duration::from_millis(100), || chunks.next().unwrap()).unwrap();
+        assert_eq!(s, "OK\r");
+    }
+
+    #[test]
+    fn read_until_ok_bails_with_the_partial_buffer_once_the_deadline_passes() {
+        let err = read_until_ok(Duration::from_millis(10), || Ok(b"12".to_vec())).unwrap_err();
+        assert!(err.to_string().contains("\"12"));
+    }
+
+    #[test]
+    fn format_tenths_scales_and_pads_to_three_digits() {
+        assert_eq!(format_tenths(12.3), "123");
+        assert_eq!(format_tenths(0.0), "000");
+        assert_eq!(format_tenths(9.9), "099");
+    }
+
+    #[test]
+    fn format_tenths_round_trips_through_the_gets_parsing_scheme() {
+        let voltage = Voltage::from(12.3);
+        let scaled = format_tenths(f64::from(voltage));
+        let parsed: f64 = format!("{}.{}", &scaled[0..2], &scaled[2..3]).parse().unwrap();
+        assert!((parsed - 12.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn energy_accumulates_the_trapezoidal_area_under_power() {
+        let mut energy = Energy::zero();
+        let p1 = Power::from_vi(Voltage::from(10.0), Current::from(1.0));
+        let p2 = Power::from_vi(Voltage::from(10.0), Current::from(2.0));
+        energy.accumulate(p1, p2, Duration::from_secs(3600));
+        assert!((energy.0.get::<watt_hour>() - 15.0).abs() < 1e-9);
+    }
+}
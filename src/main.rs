@@ -14,11 +14,43 @@ fn main() -> Result<()> {
         Some(Command::Off) => get_powsup(&cli)?.off(),
         Some(Command::On) => get_powsup(&cli)?.on(),
         Some(Command::Powercycle { off_duration }) => get_powsup(&cli)?.powercycle(off_duration),
-        Some(Command::Status { brief }) => get_powsup(&cli)?.status(brief),
-        Some(Command::Interactive { ref args }) => {
-            powsup::interactive(&mut get_powsup(&cli)?, args)
+        Some(Command::Status { brief, json }) => get_powsup(&cli)?.status(brief, json),
+        Some(Command::Set { voltage, current }) => {
+            let mut powsup = get_powsup(&cli)?;
+            if let Some(voltage) = voltage {
+                powsup.set_voltage(voltage.into())?;
+            }
+            if let Some(current) = current {
+                powsup.set_current(current.into())?;
+            }
+            Ok(())
         }
-        None => powsup::interactive(&mut get_powsup(&cli)?, &powsup::InteractiveArgs::default()),
+        Some(Command::Protection { ovp, ocp }) => {
+            let mut powsup = get_powsup(&cli)?;
+            if ovp.is_none() && ocp.is_none() {
+                println!("OVP: {}  OCP: {}", powsup.get_ovp()?, powsup.get_ocp()?);
+            }
+            if let Some(ovp) = ovp {
+                powsup.set_ovp(ovp.into())?;
+            }
+            if let Some(ocp) = ocp {
+                powsup.set_ocp(ocp.into())?;
+            }
+            Ok(())
+        }
+        Some(Command::Log {
+            file,
+            period,
+            duration,
+        }) => get_powsup(&cli)?
+            .execute(powsup::Command::Log {
+                file,
+                period,
+                duration,
+            })
+            .map(|_| ()),
+        Some(Command::Interactive { ref args }) => get_powsup(&cli)?.interactive(args),
+        None => get_powsup(&cli)?.interactive(&powsup::InteractiveArgs::default()),
     }
 }
 
@@ -28,7 +60,15 @@ fn get_powsup(cli: &Cli) -> Result<powsup::PowSup> {
         .serial_port
         .clone()
         .map_or_else(|| powsup::guess_port().context(msg), Ok)?;
-    powsup::PowSup::new(&port)
+    powsup::PowSup::new(
+        &port,
+        Some(
+            cli.timeout
+                .map_or(powsup::DEFAULT_TIMEOUT, Duration::from_millis),
+        ),
+        cli.deadline
+            .map_or(powsup::DEFAULT_READ_DEADLINE, Duration::from_millis),
+    )
 }
 
 #[derive(Parser, Debug)]
@@ -39,6 +79,12 @@ struct Cli {
     /// The serial port that the power supply is connected to.
     #[clap(short, long)]
     serial_port: Option<String>,
+    /// Per-read timeout in milliseconds, passed through to the serial port.
+    #[clap(long)]
+    timeout: Option<u64>,
+    /// Overall deadline in milliseconds for assembling a complete reply before giving up.
+    #[clap(long)]
+    deadline: Option<u64>,
     #[clap(flatten)]
     verbose: Verbosity<WarnLevel>,
 }
@@ -65,6 +111,39 @@ enum Command {
         /// Only show display value
         #[clap(short, long)]
         brief: bool,
+        /// Print the status as JSON instead of a human-readable table
+        #[clap(long)]
+        json: bool,
+    },
+    /// Program new voltage and/or current setpoints
+    Set {
+        /// The new voltage setpoint in volts
+        #[clap(short, long)]
+        voltage: Option<f64>,
+        /// The new current setpoint in amps
+        #[clap(short, long)]
+        current: Option<f64>,
+    },
+    /// Read or program the over-voltage/over-current protection thresholds
+    Protection {
+        /// The new over-voltage protection threshold in volts
+        #[clap(long)]
+        ovp: Option<f64>,
+        /// The new over-current protection threshold in amps
+        #[clap(long)]
+        ocp: Option<f64>,
+    },
+    /// Record timestamped samples to a CSV file for later analysis
+    Log {
+        /// The CSV file to write samples to
+        #[clap(short, long)]
+        file: std::path::PathBuf,
+        /// The sampling period in milliseconds
+        #[clap(short, long, default_value = "1000", value_parser = powsup::ms_parser)]
+        period: Duration,
+        /// How long to log for, in milliseconds
+        #[clap(short, long, value_parser = powsup::ms_parser)]
+        duration: Duration,
     },
     /// Run in interactive mode [default]
     Interactive {